@@ -18,6 +18,9 @@
 
 #![no_std]
 
+#[cfg(any(feature = "std", doc, test))]
+extern crate std;
+
 use core::{fmt, num};
 use core::convert::TryFrom;
 
@@ -62,6 +65,34 @@ impl Error {
 	pub const fn get_nonzero(&self) -> num::NonZeroI32 {
 		self.0
 	}
+
+	/// Returns a human-readable description of the error, in the same style
+	/// as the comments on each `Error` constant (e.g. `"No such file or
+	/// directory"`).
+	///
+	/// Errno values without a known description return `"unknown error"`.
+	#[inline]
+	pub const fn message(&self) -> &'static str {
+		match err_desc(*self) {
+			Some(desc) => desc,
+			None => "unknown error",
+		}
+	}
+
+	/// Returns the name of the `Error` constant corresponding to this error
+	/// number (e.g. `"ENOENT"`), or `None` if the error number is not
+	/// recognized.
+	#[inline]
+	pub const fn name(&self) -> Option<&'static str> {
+		err_name(*self)
+	}
+
+	/// Parses the name of an `Error` constant (e.g. `"ENOENT"`) and returns
+	/// the corresponding error, or `None` if the name is not recognized.
+	#[inline]
+	pub fn from_name(name: &str) -> Option<Error> {
+		err_from_name(name)
+	}
 }
 
 #[cold]
@@ -252,7 +283,7 @@ impl_partial_eq_nonzero!(num::NonZeroU16, i32);
 impl_partial_eq_nonzero!(num::NonZeroU32, i64);
 
 macro_rules! errno_constants {
-	( $( $(#[$meta:meta])* $name:ident = $value:literal , )+ ) => {
+	( $( $(#[$meta:meta])* $name:ident = $value:literal , desc = $desc:literal , )+ ) => {
 		$(
 			$(#[$meta])*
 			pub const $name: $crate::Error = unsafe {
@@ -269,6 +300,26 @@ macro_rules! errno_constants {
 				_ => None,
 			}
 		}
+
+		#[inline]
+		const fn err_desc(err: $crate::Error) -> Option<&'static str> {
+			match err.0.get() {
+			$(
+				$value => Some($desc),
+			)*
+				_ => None,
+			}
+		}
+
+		#[inline]
+		fn err_from_name(name: &str) -> Option<$crate::Error> {
+			match name {
+			$(
+				stringify!($name) => Some($crate::$name),
+			)*
+				_ => None,
+			}
+		}
 	}
 }
 
@@ -281,6 +332,12 @@ impl fmt::Debug for Error {
 	}
 }
 
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} (os error {})", self.message(), self.0.get())
+	}
+}
+
 #[cfg(feature = "posix-traits")]
 const fn from_posix(err: posix_errno::Error) -> Option<Error> {
 	use posix_errno::Error as P;
@@ -386,241 +443,486 @@ impl PartialEq<Error> for posix_errno::Error {
 	}
 }
 
+#[cfg(any(feature = "libc", doc))]
+extern "C" {
+	fn __error() -> *mut libc::c_int;
+}
+
+#[cfg(any(feature = "libc", doc))]
+impl Error {
+	/// Returns the most recent error recorded in the calling thread's C
+	/// `errno`, or `None` if it is currently zero.
+	///
+	/// Requires the `libc` feature.
+	#[inline]
+	pub fn last() -> Option<Error> {
+		Error::new(unsafe { *__error() })
+	}
+}
+
+/// Clears the calling thread's C `errno` by setting it to zero.
+///
+/// Requires the `libc` feature.
+#[cfg(any(feature = "libc", doc))]
+#[inline]
+pub fn clear() {
+	unsafe {
+		*__error() = 0;
+	}
+}
+
+/// Converts a raw system call return value into a `Result`, using
+/// [`Error::last`] to populate the `Err` case.
+///
+/// This follows the common C convention of returning a negative value (most
+/// often `-1`) on failure and recording the cause in `errno`.
+///
+/// If `res` is negative but the thread's `errno` is unexpectedly `0` (for
+/// example because the caller forgot to [`clear()`] it first), this returns
+/// [`EIO`] rather than panicking.
+///
+/// Requires the `libc` feature.
+#[cfg(any(feature = "libc", doc))]
+pub fn from_ffi(res: libc::c_int) -> Result<libc::c_int, Error> {
+	if res >= 0 {
+		Ok(res)
+	} else {
+		Err(Error::last().unwrap_or(EIO))
+	}
+}
+
+#[cfg(any(feature = "std", doc))]
+impl From<Error> for std::io::Error {
+	#[inline]
+	fn from(err: Error) -> std::io::Error {
+		std::io::Error::from_raw_os_error(err.get())
+	}
+}
+
+#[cfg(any(feature = "std", doc))]
+impl TryFrom<&std::io::Error> for Error {
+	type Error = ();
+
+	/// Converts from `std::io::Error` to `Error`.
+	///
+	/// Returns `Err(())` if `err` does not carry a raw OS error code, or if
+	/// that code is zero.
+	fn try_from(err: &std::io::Error) -> Result<Error, ()> {
+		match err.raw_os_error() {
+			Some(errno) => Error::new(errno).ok_or(()),
+			None => Err(()),
+		}
+	}
+}
+
+#[cfg(any(feature = "serde", doc))]
+impl serde::Serialize for Error {
+	/// Serializes as the constant's name (e.g. `"ENOENT"`), or as the raw
+	/// error number if the name is not known.
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		match self.name() {
+			Some(name) => serializer.serialize_str(name),
+			None => serializer.serialize_i32(self.get()),
+		}
+	}
+}
+
+#[cfg(any(feature = "serde", doc))]
+impl<'de> serde::Deserialize<'de> for Error {
+	/// Deserializes from either a constant's name (e.g. `"ENOENT"`) or a
+	/// nonzero integer error number.
+	fn deserialize<D>(deserializer: D) -> Result<Error, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct ErrorVisitor;
+
+		impl<'de> serde::de::Visitor<'de> for ErrorVisitor {
+			type Value = Error;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("a macOS errno name or nonzero integer")
+			}
+
+			fn visit_str<E>(self, v: &str) -> Result<Error, E>
+			where
+				E: serde::de::Error,
+			{
+				Error::from_name(v).ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+			}
+
+			fn visit_i64<E>(self, v: i64) -> Result<Error, E>
+			where
+				E: serde::de::Error,
+			{
+				match i32::try_from(v).ok().and_then(Error::new) {
+					Some(err) => Ok(err),
+					None => Err(E::invalid_value(serde::de::Unexpected::Signed(v), &self)),
+				}
+			}
+
+			fn visit_u64<E>(self, v: u64) -> Result<Error, E>
+			where
+				E: serde::de::Error,
+			{
+				match i32::try_from(v).ok().and_then(Error::new) {
+					Some(err) => Ok(err),
+					None => Err(E::invalid_value(serde::de::Unexpected::Unsigned(v), &self)),
+				}
+			}
+		}
+
+		deserializer.deserialize_any(ErrorVisitor)
+	}
+}
+
 errno_constants! {
 	// https://github.com/apple-oss-distributions/xnu/blob/xnu-11215.1.10/bsd/sys/errno.h
 
 	/// Operation not permitted
-	EPERM = 1,
+	EPERM = 1, desc = "Operation not permitted",
 	/// No such file or directory
-	ENOENT = 2,
+	ENOENT = 2, desc = "No such file or directory",
 	/// No such process
-	ESRCH = 3,
+	ESRCH = 3, desc = "No such process",
 	/// Interrupted system call
-	EINTR = 4,
+	EINTR = 4, desc = "Interrupted system call",
 	/// Input/output error
-	EIO = 5,
+	EIO = 5, desc = "Input/output error",
 	/// Device not configured
-	ENXIO = 6,
+	ENXIO = 6, desc = "Device not configured",
 	/// Argument list too long
-	E2BIG = 7,
+	E2BIG = 7, desc = "Argument list too long",
 	/// Exec format error
-	ENOEXEC = 8,
+	ENOEXEC = 8, desc = "Exec format error",
 	/// Bad file descriptor
-	EBADF = 9,
+	EBADF = 9, desc = "Bad file descriptor",
 	/// No child processes
-	ECHILD = 10,
+	ECHILD = 10, desc = "No child processes",
 	/// Resource deadlock avoided
-	EDEADLK = 11,
+	EDEADLK = 11, desc = "Resource deadlock avoided",
 	/// Cannot allocate memory
-	ENOMEM = 12,
+	ENOMEM = 12, desc = "Cannot allocate memory",
 	/// Permission denied
-	EACCES = 13,
+	EACCES = 13, desc = "Permission denied",
 	/// Bad address
-	EFAULT = 14,
+	EFAULT = 14, desc = "Bad address",
 	/// Block device required
-	ENOTBLK = 15,
+	ENOTBLK = 15, desc = "Block device required",
 	/// Device / Resource busy
-	EBUSY = 16,
+	EBUSY = 16, desc = "Device / Resource busy",
 	/// File exists
-	EEXIST = 17,
+	EEXIST = 17, desc = "File exists",
 	/// Cross-device link
-	EXDEV = 18,
+	EXDEV = 18, desc = "Cross-device link",
 	/// Operation not supported by device
-	ENODEV = 19,
+	ENODEV = 19, desc = "Operation not supported by device",
 	/// Not a directory
-	ENOTDIR = 20,
+	ENOTDIR = 20, desc = "Not a directory",
 	/// Is a directory
-	EISDIR = 21,
+	EISDIR = 21, desc = "Is a directory",
 	/// Invalid argument
-	EINVAL = 22,
+	EINVAL = 22, desc = "Invalid argument",
 	/// Too many open files in system
-	ENFILE = 23,
+	ENFILE = 23, desc = "Too many open files in system",
 	/// Too many open files
-	EMFILE = 24,
+	EMFILE = 24, desc = "Too many open files",
 	/// Inappropriate ioctl for device
-	ENOTTY = 25,
+	ENOTTY = 25, desc = "Inappropriate ioctl for device",
 	/// Text file busy
-	ETXTBSY = 26,
+	ETXTBSY = 26, desc = "Text file busy",
 	/// File too large
-	EFBIG = 27,
+	EFBIG = 27, desc = "File too large",
 	/// No space left on device
-	ENOSPC = 28,
+	ENOSPC = 28, desc = "No space left on device",
 	/// Illegal seek
-	ESPIPE = 29,
+	ESPIPE = 29, desc = "Illegal seek",
 	/// Read-only file system
-	EROFS = 30,
+	EROFS = 30, desc = "Read-only file system",
 	/// Too many links
-	EMLINK = 31,
+	EMLINK = 31, desc = "Too many links",
 	/// Broken pipe
-	EPIPE = 32,
+	EPIPE = 32, desc = "Broken pipe",
 
 	/// Numerical argument out of domain
-	EDOM = 33,
+	EDOM = 33, desc = "Numerical argument out of domain",
 	/// Result too large
-	ERANGE = 34,
+	ERANGE = 34, desc = "Result too large",
 
 	/// Resource temporarily unavailable
-	EAGAIN = 35,
+	EAGAIN = 35, desc = "Resource temporarily unavailable",
 	/// Operation now in progress
-	EINPROGRESS = 36,
+	EINPROGRESS = 36, desc = "Operation now in progress",
 	/// Operation already in progress
-	EALREADY = 37,
+	EALREADY = 37, desc = "Operation already in progress",
 
 	/// Socket operation on non-socket
-	ENOTSOCK = 38,
+	ENOTSOCK = 38, desc = "Socket operation on non-socket",
 	/// Destination address required
-	EDESTADDRREQ = 39,
+	EDESTADDRREQ = 39, desc = "Destination address required",
 	/// Message too long
-	EMSGSIZE = 40,
+	EMSGSIZE = 40, desc = "Message too long",
 	/// Protocol wrong type for socket
-	EPROTOTYPE = 41,
+	EPROTOTYPE = 41, desc = "Protocol wrong type for socket",
 	/// Protocol not available
-	ENOPROTOOPT = 42,
+	ENOPROTOOPT = 42, desc = "Protocol not available",
 	/// Protocol not supported
-	EPROTONOSUPPORT = 43,
+	EPROTONOSUPPORT = 43, desc = "Protocol not supported",
 	/// Socket type not supported
-	ESOCKTNOSUPPORT = 44,
+	ESOCKTNOSUPPORT = 44, desc = "Socket type not supported",
 	/// Operation not supported
-	ENOTSUP = 45,
+	ENOTSUP = 45, desc = "Operation not supported",
 
 	/// Protocol family not supported
-	EPFNOSUPPORT = 46,
+	EPFNOSUPPORT = 46, desc = "Protocol family not supported",
 	/// Address family not supported by protocol family
-	EAFNOSUPPORT = 47,
+	EAFNOSUPPORT = 47, desc = "Address family not supported by protocol family",
 	/// Address already in use
-	EADDRINUSE = 48,
+	EADDRINUSE = 48, desc = "Address already in use",
 	/// Can't assign requested address
-	EADDRNOTAVAIL = 49,
+	EADDRNOTAVAIL = 49, desc = "Can't assign requested address",
 
 	/// Network is down
-	ENETDOWN = 50,
+	ENETDOWN = 50, desc = "Network is down",
 	/// Network is unreachable
-	ENETUNREACH = 51,
+	ENETUNREACH = 51, desc = "Network is unreachable",
 	/// Network dropped connection on reset
-	ENETRESET = 52,
+	ENETRESET = 52, desc = "Network dropped connection on reset",
 	/// Software caused connection abort
-	ECONNABORTED = 53,
+	ECONNABORTED = 53, desc = "Software caused connection abort",
 	/// Connection reset by peer
-	ECONNRESET = 54,
+	ECONNRESET = 54, desc = "Connection reset by peer",
 	/// No buffer space available
-	ENOBUFS = 55,
+	ENOBUFS = 55, desc = "No buffer space available",
 	/// Socket is already connected
-	EISCONN = 56,
+	EISCONN = 56, desc = "Socket is already connected",
 	/// Socket is not connected
-	ENOTCONN = 57,
+	ENOTCONN = 57, desc = "Socket is not connected",
 	/// Can't send after socket shutdown
-	ESHUTDOWN = 58,
+	ESHUTDOWN = 58, desc = "Can't send after socket shutdown",
 	/// Too many references: can't splice
-	ETOOMANYREFS = 59,
+	ETOOMANYREFS = 59, desc = "Too many references: can't splice",
 	/// Operation timed out
-	ETIMEDOUT = 60,
+	ETIMEDOUT = 60, desc = "Operation timed out",
 	/// Connection refused
-	ECONNREFUSED = 61,
+	ECONNREFUSED = 61, desc = "Connection refused",
 	/// Too many levels of symbolic links
-	ELOOP = 62,
+	ELOOP = 62, desc = "Too many levels of symbolic links",
 	/// File name too long
-	ENAMETOOLONG = 63,
+	ENAMETOOLONG = 63, desc = "File name too long",
 
 	/// Host is down
-	EHOSTDOWN = 64,
+	EHOSTDOWN = 64, desc = "Host is down",
 	/// No route to host
-	EHOSTUNREACH = 65,
+	EHOSTUNREACH = 65, desc = "No route to host",
 	/// Directory not empty
-	ENOTEMPTY = 66,
+	ENOTEMPTY = 66, desc = "Directory not empty",
 
 	/// Too many processes
-	EPROCLIM = 67,
+	EPROCLIM = 67, desc = "Too many processes",
 	/// Too many users
-	EUSERS = 68,
+	EUSERS = 68, desc = "Too many users",
 	/// Disc quota exceeded
-	EDQUOT = 69,
+	EDQUOT = 69, desc = "Disc quota exceeded",
 
 	/// Stale NFS file handle
-	ESTALE = 70,
+	ESTALE = 70, desc = "Stale NFS file handle",
 	/// Too many levels of remote in path
-	EREMOTE = 71,
+	EREMOTE = 71, desc = "Too many levels of remote in path",
 	/// RPC struct is bad
-	EBADRPC = 72,
+	EBADRPC = 72, desc = "RPC struct is bad",
 	/// RPC version wrong
-	ERPCMISMATCH = 73,
+	ERPCMISMATCH = 73, desc = "RPC version wrong",
 	/// RPC prog. not avail
-	EPROGUNAVAIL = 74,
+	EPROGUNAVAIL = 74, desc = "RPC prog. not avail",
 	/// Program version wrong
-	EPROGMISMATCH = 75,
+	EPROGMISMATCH = 75, desc = "Program version wrong",
 	/// Bad procedure for program
-	EPROCUNAVAIL = 76,
+	EPROCUNAVAIL = 76, desc = "Bad procedure for program",
 
 	/// No locks available
-	ENOLCK = 77,
+	ENOLCK = 77, desc = "No locks available",
 	/// Function not implemented
-	ENOSYS = 78,
+	ENOSYS = 78, desc = "Function not implemented",
 
 	/// Inappropriate file type or format
-	EFTYPE = 79,
+	EFTYPE = 79, desc = "Inappropriate file type or format",
 	/// Authentication error
-	EAUTH = 80,
+	EAUTH = 80, desc = "Authentication error",
 	/// Need authenticator
-	ENEEDAUTH = 81,
+	ENEEDAUTH = 81, desc = "Need authenticator",
 
 	/// Device power is off
-	EPWROFF = 82,
+	EPWROFF = 82, desc = "Device power is off",
 	/// Device error, e.g. paper out
-	EDEVERR = 83,
+	EDEVERR = 83, desc = "Device error, e.g. paper out",
 
 	/// Value too large to be stored in data type
-	EOVERFLOW = 84,
+	EOVERFLOW = 84, desc = "Value too large to be stored in data type",
 
 	/// Bad executable
-	EBADEXEC = 85,
+	EBADEXEC = 85, desc = "Bad executable",
 	/// Bad CPU type in executable
-	EBADARCH = 86,
+	EBADARCH = 86, desc = "Bad CPU type in executable",
 	/// Shared library version mismatch
-	ESHLIBVERS = 87,
+	ESHLIBVERS = 87, desc = "Shared library version mismatch",
 	/// Malformed Macho file
-	EBADMACHO = 88,
+	EBADMACHO = 88, desc = "Malformed Macho file",
 
 	/// Operation canceled
-	ECANCELED = 89,
+	ECANCELED = 89, desc = "Operation canceled",
 
 	/// Identifier removed
-	EIDRM = 90,
+	EIDRM = 90, desc = "Identifier removed",
 	/// No message of desired type
-	ENOMSG = 91,
+	ENOMSG = 91, desc = "No message of desired type",
 	/// Illegal byte sequence
-	EILSEQ = 92,
+	EILSEQ = 92, desc = "Illegal byte sequence",
 	/// Attribute not found
-	ENOATTR = 93,
+	ENOATTR = 93, desc = "Attribute not found",
 
 	/// Bad message
-	EBADMSG = 94,
+	EBADMSG = 94, desc = "Bad message",
 	/// Reserved
-	EMULTIHOP = 95,
+	EMULTIHOP = 95, desc = "Reserved",
 	/// No message available on STREAM
-	ENODATA = 96,
+	ENODATA = 96, desc = "No message available on STREAM",
 	/// Reserved
-	ENOLINK = 97,
+	ENOLINK = 97, desc = "Reserved",
 	/// No STREAM resources
-	ENOSR = 98,
+	ENOSR = 98, desc = "No STREAM resources",
 	/// Not a STREAM
-	ENOSTR = 99,
+	ENOSTR = 99, desc = "Not a STREAM",
 	/// Protocol error
-	EPROTO = 100,
+	EPROTO = 100, desc = "Protocol error",
 	/// STREAM ioctl timeout
-	ETIME = 101,
+	ETIME = 101, desc = "STREAM ioctl timeout",
 
 	/// Operation not supported on socket
-	EOPNOTSUPP = 102,
+	EOPNOTSUPP = 102, desc = "Operation not supported on socket",
 	/// No such policy registered
-	ENOPOLICY = 103,
+	ENOPOLICY = 103, desc = "No such policy registered",
 
 	/// State not recoverable
-	ENOTRECOVERABLE = 104,
+	ENOTRECOVERABLE = 104, desc = "State not recoverable",
 	/// Previous owner died
-	EOWNERDEAD = 105,
+	EOWNERDEAD = 105, desc = "Previous owner died",
 
 	/// Interface output queue is full
-	EQFULL = 106,
+	EQFULL = 106, desc = "Interface output queue is full",
 }
 
 /// Operation would block (alias for [`EAGAIN`])
 pub const EWOULDBLOCK: Error = EAGAIN;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn message_known_and_unknown() {
+		assert_eq!("No such file or directory", ENOENT.message());
+		assert_eq!("unknown error", Error::new(999).unwrap().message());
+	}
+
+	#[test]
+	fn display_known_and_unknown() {
+		assert_eq!(
+			"No such file or directory (os error 2)",
+			std::format!("{}", ENOENT),
+		);
+		assert_eq!(
+			"unknown error (os error 999)",
+			std::format!("{}", Error::new(999).unwrap()),
+		);
+	}
+
+	#[test]
+	fn name_known_and_unknown() {
+		assert_eq!(Some("ENOENT"), ENOENT.name());
+		assert_eq!(None, Error::new(999).unwrap().name());
+	}
+
+	#[test]
+	fn from_name_known_and_unknown() {
+		assert_eq!(Some(ENOENT), Error::from_name("ENOENT"));
+		assert_eq!(None, Error::from_name("ENOTAREALERRNO"));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn io_error_round_trip() {
+		let io_err: std::io::Error = ENOENT.into();
+		assert_eq!(Some(2), io_err.raw_os_error());
+		assert_eq!(Ok(ENOENT), Error::try_from(&io_err));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn io_error_without_os_code_fails() {
+		let io_err = std::io::Error::new(std::io::ErrorKind::Other, "not an os error");
+		assert_eq!(Err(()), Error::try_from(&io_err));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn io_error_with_zero_os_code_fails() {
+		let io_err = std::io::Error::from_raw_os_error(0);
+		assert_eq!(Err(()), Error::try_from(&io_err));
+	}
+
+	#[cfg(feature = "libc")]
+	#[test]
+	fn last_clear_and_from_ffi() {
+		clear();
+		assert_eq!(None, Error::last());
+
+		unsafe {
+			*__error() = libc::ENOENT;
+		}
+		assert_eq!(Some(ENOENT), Error::last());
+		clear();
+
+		assert_eq!(Ok(4), from_ffi(4));
+
+		unsafe {
+			*__error() = libc::EACCES;
+		}
+		assert_eq!(Err(EACCES), from_ffi(-1));
+		clear();
+
+		assert_eq!(Err(EIO), from_ffi(-1));
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_round_trips_known_name() {
+		let json = serde_json::to_string(&ENOENT).unwrap();
+		assert_eq!(json, "\"ENOENT\"");
+		assert_eq!(ENOENT, serde_json::from_str::<Error>(&json).unwrap());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_falls_back_to_integer_for_unknown_errno() {
+		let err = Error::new(12345).unwrap();
+		let json = serde_json::to_string(&err).unwrap();
+		assert_eq!(json, "12345");
+		assert_eq!(err, serde_json::from_str::<Error>(&json).unwrap());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_rejects_zero() {
+		assert!(serde_json::from_str::<Error>("0").is_err());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_rejects_unknown_name() {
+		assert!(serde_json::from_str::<Error>("\"ENOTAREALERRNO\"").is_err());
+	}
+}